@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::cfr::InfoSetMap;
+use crate::game::{Game, Player};
+
+/// 特定の情報集合に到達する配り(`deal`)とその到達確率(相手のリーチ確率×チャンス確率)の組
+type WeightedDeals<G> = Vec<(<G as Game>::Deal, f64)>;
+
+/// `state`以下の部分木について、`responder`がベストレスポンスした場合の
+/// （チャンス確率・相手のリーチ確率込みの）期待値を計算する
+///
+/// `deals`には、この`state`に到達しうる配りと、そこに至るまでの重み
+/// （チャンス確率×相手の平均戦略に基づくリーチ確率）を渡す。
+/// ベストレスポンス側の手番では、同じ情報集合に属する配りをまとめて
+/// グループ化し、各アクションについて継続価値を計算した上で最大のものを選ぶ
+/// （同じ情報集合では同じアクションしか選べないため）。
+/// 相手の手番では、相手の平均戦略の確率でリーチ確率を更新しながら継続価値を合算する。
+///
+/// 制約: グループ化は「現在の`state`に到達した配り」の中だけで行っている。
+/// Kuhn Pokerのように情報集合キー（カード+履歴）がゲーム木のノードと1対1に対応する
+/// ゲームではこれで正しいが、同じ情報集合キーに複数のノード（例えば複数ラウンドの
+/// ベッティングを持つLeduc Pokerで、ラウンドをまたいで履歴が合流するケース）が
+/// 対応しうるゲームでは、ノードごとに独立して最大値を取ってしまい「同じ情報集合では
+/// 同じアクションしか選べない」という制約を満たせず、搾取可能性の値が不正確になる。
+/// そのようなゲームを追加する場合は、情報集合キーからノードをまたいでグローバルに
+/// 最良アクションを選ぶよう書き換える必要がある。
+fn best_response_recursive<G: Game>(
+    state: &G::State,
+    deals: WeightedDeals<G>,
+    responder: Player,
+    info_sets: &InfoSetMap<G>,
+) -> f64 {
+    if G::is_terminal(state) {
+        return deals
+            .iter()
+            .map(|(deal, weight)| {
+                let payoff_p1 = G::payoff(deal, state) as f64;
+                let payoff = match responder {
+                    Player::Player1 => payoff_p1,
+                    Player::Player2 => -payoff_p1,
+                };
+                weight * payoff
+            })
+            .sum();
+    }
+
+    let current_player = G::current_player(state);
+    let actions = G::legal_actions(state);
+
+    // この状態に到達する配りを、現在の手番のプレイヤーが区別できる情報集合ごとに分ける
+    let mut groups: HashMap<G::InfoSetKey, WeightedDeals<G>> = HashMap::new();
+    for (deal, weight) in deals {
+        let key = G::info_set_key(&deal, state);
+        groups.entry(key).or_insert_with(Vec::new).push((deal, weight));
+    }
+
+    if current_player == responder {
+        // ベストレスポンス側: 情報集合ごとに最も価値の高いアクションを選ぶ
+        groups
+            .into_values()
+            .map(|group_deals| {
+                actions
+                    .iter()
+                    .map(|&action| {
+                        let next_state = G::next_state(state, action);
+                        best_response_recursive::<G>(&next_state, group_deals.clone(), responder, info_sets)
+                    })
+                    .fold(f64::NEG_INFINITY, f64::max)
+            })
+            .sum()
+    } else {
+        // 固定された相手側: 平均戦略の確率でリーチ確率を更新しながら合算する
+        groups
+            .into_iter()
+            .map(|(key, group_deals)| {
+                let avg_strategy = info_sets.get(&key).map(|node| node.get_average_strategy());
+                actions
+                    .iter()
+                    .map(|&action| {
+                        // 未学習の情報集合（学習中に一度も到達しなかった手順）は均等戦略とみなす
+                        let prob = match &avg_strategy {
+                            Some(strategy) => *strategy.get(&action).unwrap_or(&0.0),
+                            None => 1.0 / actions.len() as f64,
+                        };
+                        if prob <= 0.0 {
+                            return 0.0;
+                        }
+                        let next_state = G::next_state(state, action);
+                        let weighted_deals: WeightedDeals<G> = group_deals
+                            .iter()
+                            .map(|(deal, weight)| (*deal, weight * prob))
+                            .collect();
+                        best_response_recursive::<G>(&next_state, weighted_deals, responder, info_sets)
+                    })
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+}
+
+/// 相手を`info_sets`の平均戦略に固定したときの、`responder`にとってのベストレスポンス価値
+///
+/// ゲーム木を全ての配りについて辿り、ベストレスポンス側の各情報集合で
+/// 最も価値の高いアクションを選んだ場合の期待値（チャンス確率込み）を返す。
+pub fn best_response_value<G: Game>(info_sets: &InfoSetMap<G>, responder: Player) -> f64 {
+    let deals = G::chance_deals();
+    let chance_prob = 1.0 / deals.len() as f64;
+    let weighted_deals: WeightedDeals<G> = deals.into_iter().map(|deal| (deal, chance_prob)).collect();
+
+    let state = G::initial_state();
+    best_response_recursive::<G>(&state, weighted_deals, responder, info_sets)
+}
+
+/// 現在の戦略の搾取可能性(Exploitability)を計算する
+///
+/// 両プレイヤーそれぞれについてベストレスポンス価値を求めて合計したもの（NashConv）。
+/// 真のGTO戦略に収束するほど0に近づく。
+pub fn exploitability<G: Game>(info_sets: &InfoSetMap<G>) -> f64 {
+    best_response_value::<G>(info_sets, Player::Player1) + best_response_value::<G>(info_sets, Player::Player2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfr::train;
+    use crate::kuhn::KuhnGame;
+
+    #[test]
+    fn test_exploitability_decreases_with_training() {
+        let early = train::<KuhnGame>(10);
+        let late = train::<KuhnGame>(10_000);
+
+        let early_exploit = exploitability::<KuhnGame>(&early);
+        let late_exploit = exploitability::<KuhnGame>(&late);
+
+        assert!(late_exploit < early_exploit);
+    }
+
+    #[test]
+    fn test_exploitability_is_nonnegative() {
+        let info_sets = train::<KuhnGame>(1_000);
+        assert!(exploitability::<KuhnGame>(&info_sets) >= 0.0);
+    }
+
+    #[test]
+    fn test_best_response_values_are_symmetric_in_sign() {
+        // 学習が十分進めば、P1のベストレスポンス価値はゲーム値 -1/18、P2のそれは +1/18 に近づき、
+        // 符号が逆で大きさの等しい値になる。両者の差は 1/9 (約0.111) に近づくので 0.2 未満に収まる
+        let info_sets = train::<KuhnGame>(10_000);
+        let br1 = best_response_value::<KuhnGame>(&info_sets, Player::Player1);
+        let br2 = best_response_value::<KuhnGame>(&info_sets, Player::Player2);
+
+        assert!((br1 - br2).abs() < 0.2);
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+use std::hash::Hash;
+
+/// 2人対戦ゲームにおけるプレイヤー
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Player {
+    Player1,
+    Player2,
+}
+
+impl Player {
+    pub fn other(&self) -> Player {
+        match self {
+            Player::Player1 => Player::Player2,
+            Player::Player2 => Player::Player1,
+        }
+    }
+}
+
+/// CFRソルバーが扱うゲームを表すトレイト
+///
+/// Kuhn Poker固有だったロジックをこのトレイトの実装として切り出すことで、
+/// `cfr`/`train`/`InfoSetMap` をゲームの種類に依存しないコードにできる。
+/// Leduc Pokerのようにボードカードやベッティングラウンドが増えるゲームも、
+/// ソルバー本体を書き換えることなくこのトレイトを実装するだけで追加できる。
+pub trait Game {
+    /// プレイヤーが選択できるアクション
+    type Action: Copy + Eq + Hash + fmt::Debug;
+    /// ゲーム木のノード（手番・履歴などの公開情報）
+    type State: Clone;
+    /// チャンスノードで配られる非公開情報（カードなど）
+    type Deal: Copy;
+    /// 情報集合を一意に識別するキー
+    type InfoSetKey: Eq + Hash + Clone + Ord + fmt::Display;
+
+    /// ゲーム開始時の初期状態を返す
+    fn initial_state() -> Self::State;
+
+    /// 現在の状態で選択可能なアクションの一覧を返す
+    fn legal_actions(state: &Self::State) -> Vec<Self::Action>;
+
+    /// アクションを適用した後の状態を返す
+    fn next_state(state: &Self::State, action: Self::Action) -> Self::State;
+
+    /// 終端状態かどうかを判定する
+    fn is_terminal(state: &Self::State) -> bool;
+
+    /// 現在の手番のプレイヤーを返す
+    fn current_player(state: &Self::State) -> Player;
+
+    /// 終端状態における、プレイヤー1から見た報酬を返す
+    fn payoff(deal: &Self::Deal, state: &Self::State) -> i32;
+
+    /// 手番のプレイヤーの非公開情報と公開履歴から情報集合キーを生成する
+    fn info_set_key(deal: &Self::Deal, state: &Self::State) -> Self::InfoSetKey;
+
+    /// チャンスノードで起こりうる配りの組み合わせを全て返す
+    fn chance_deals() -> Vec<Self::Deal>;
+}
@@ -1,7 +1,11 @@
 use std::{fmt, vec};
 use std::hash::{Hash, Hasher};
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+use serde::{Deserialize, Serialize};
+
+use crate::game::{Game, Player};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum Action {
     Check,
     Bet,
@@ -52,21 +56,6 @@ impl History {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub enum Player {
-    Player1,
-    Player2,
-}
-
-impl Player {
-    fn other(&self) -> Player {
-        match self {
-            Player::Player1 => Player::Player2,
-            Player::Player2 => Player::Player1,
-        }
-    }
-}
-
 #[derive(Clone, Debug)]
 pub struct GameState {
     pub history: History,
@@ -127,6 +116,152 @@ impl GameState {
     }
 }
 
+/// Kuhn Pokerのカード (J=11, Q=12, K=13)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Card {
+    Jack = 11,
+    Queen = 12,
+    King = 13,
+}
+
+impl Card {
+    /// カードを文字に変換
+    pub fn to_char(&self) -> char {
+        match self {
+            Card::Jack => 'J',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+        }
+    }
+
+    /// カードの強さを数値で取得
+    pub fn rank(&self) -> i32 {
+        *self as i32
+    }
+
+    /// 文字からカードに変換（`to_char`の逆変換）
+    pub fn from_char(c: char) -> Card {
+        match c {
+            'J' => Card::Jack,
+            'Q' => Card::Queen,
+            'K' => Card::King,
+            _ => panic!("invalid card character: {c}"),
+        }
+    }
+
+    /// デッキに含まれる全てのカード
+    pub fn all() -> [Card; 3] {
+        [Card::Jack, Card::Queen, Card::King]
+    }
+}
+
+/// 2人のプレイヤーにカードを配る全ての組み合わせを返す
+/// Kuhn Pokerでは3枚(J,Q,K)から2枚を選んで配る
+pub fn deal_cards() -> Vec<(Card, Card)> {
+    use Card::*;
+    vec![
+        (Jack, Queen),
+        (Jack, King),
+        (Queen, Jack),
+        (Queen, King),
+        (King, Jack),
+        (King, Queen),
+    ]
+}
+
+/// 終端状態での報酬を計算
+/// card1: プレイヤー1のカード
+/// card2: プレイヤー2のカード
+/// history: アクション履歴（文字列）
+/// 返り値: プレイヤー1から見た報酬（プレイヤー2は符号を反転）
+pub fn get_payoff(card1: Card, card2: Card, history: &str) -> i32 {
+    // 履歴を解析（簡易的に文字列で判定）
+    match history {
+        // Check-Check: ショーダウン、ポット=2
+        "Check-Check" => {
+            if card1.rank() > card2.rank() { 1 } else { -1 }
+        }
+
+        // Bet-Fold: P1がBet、P2がFold → P1が1チップ獲得
+        "Bet-Fold" => 1,
+
+        // Check-Bet-Fold: P1 Check、P2 Bet、P1 Fold → P1が1チップ失う
+        "Check-Bet-Fold" => -1,
+
+        // Bet-Call: P1がBet、P2がCall → ショーダウン、ポット=4
+        "Bet-Call" => {
+            if card1.rank() > card2.rank() { 2 } else { -2 }
+        }
+
+        // Check-Bet-Call: P1 Check、P2 Bet、P1 Call → ショーダウン、ポット=4
+        "Check-Bet-Call" => {
+            if card1.rank() > card2.rank() { 2 } else { -2 }
+        }
+
+        _ => 0, // それ以外（終端でない場合など）
+    }
+}
+
+/// 情報集合のキーを生成
+/// card: プレイヤーのカード ('J', 'Q', 'K')
+/// history: アクション履歴の文字列
+pub fn make_info_set_key(card: char, history: &str) -> String {
+    if history.is_empty() {
+        format!("{}", card)
+    } else {
+        format!("{}-{}", card, history)
+    }
+}
+
+/// `Game`トレイトのKuhn Poker実装
+///
+/// ソルバー本体(`cfr`/`train`)はこの実装だけを通してゲームとやり取りするため、
+/// Leduc Pokerなど別のゲームを追加するときはこの型に相当する実装を用意すればよい。
+pub struct KuhnGame;
+
+impl Game for KuhnGame {
+    type Action = Action;
+    type State = GameState;
+    type Deal = (Card, Card);
+    type InfoSetKey = String;
+
+    fn initial_state() -> Self::State {
+        GameState::new()
+    }
+
+    fn legal_actions(state: &Self::State) -> Vec<Self::Action> {
+        state.legal_actions()
+    }
+
+    fn next_state(state: &Self::State, action: Self::Action) -> Self::State {
+        state.next_state(action)
+    }
+
+    fn is_terminal(state: &Self::State) -> bool {
+        state.terminal
+    }
+
+    fn current_player(state: &Self::State) -> Player {
+        state.current_player
+    }
+
+    fn payoff(deal: &Self::Deal, state: &Self::State) -> i32 {
+        get_payoff(deal.0, deal.1, &state.history.to_string())
+    }
+
+    fn info_set_key(deal: &Self::Deal, state: &Self::State) -> Self::InfoSetKey {
+        let card = match state.current_player {
+            Player::Player1 => deal.0,
+            Player::Player2 => deal.1,
+        };
+        make_info_set_key(card.to_char(), &state.history.to_string())
+    }
+
+    fn chance_deals() -> Vec<Self::Deal> {
+        deal_cards()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,4 +305,85 @@ mod tests {
         assert!(state.terminal);
 
     }
+
+    #[test]
+    fn test_card_rank() {
+        assert_eq!(Card::Jack.rank(), 11);
+        assert_eq!(Card::Queen.rank(), 12);
+        assert_eq!(Card::King.rank(), 13);
+        assert!(Card::King.rank() > Card::Queen.rank());
+    }
+
+    #[test]
+    fn test_card_to_char() {
+        assert_eq!(Card::Jack.to_char(), 'J');
+        assert_eq!(Card::Queen.to_char(), 'Q');
+        assert_eq!(Card::King.to_char(), 'K');
+    }
+
+    #[test]
+    fn test_card_from_char_round_trip() {
+        for card in Card::all() {
+            assert_eq!(Card::from_char(card.to_char()), card);
+        }
+    }
+
+    #[test]
+    fn test_deal_cards() {
+        let deals = deal_cards();
+        assert_eq!(deals.len(), 6); // 3枚から2枚選ぶ順列 = 3*2 = 6
+
+        // 最初の配布がJack-Queenであることを確認
+        assert_eq!(deals[0], (Card::Jack, Card::Queen));
+    }
+
+    #[test]
+    fn test_payoff_check_check() {
+        // Check-Check: ショーダウン
+        assert_eq!(get_payoff(Card::King, Card::Queen, "Check-Check"), 1);  // Kが勝つ
+        assert_eq!(get_payoff(Card::Jack, Card::Queen, "Check-Check"), -1); // Jが負ける
+    }
+
+    #[test]
+    fn test_payoff_bet_fold() {
+        // Bet-Fold: P1が1チップ獲得
+        assert_eq!(get_payoff(Card::Jack, Card::King, "Bet-Fold"), 1);
+    }
+
+    #[test]
+    fn test_payoff_bet_call() {
+        // Bet-Call: ショーダウン、ポット=4
+        assert_eq!(get_payoff(Card::King, Card::Jack, "Bet-Call"), 2);   // Kが勝つ
+        assert_eq!(get_payoff(Card::Jack, Card::King, "Bet-Call"), -2);  // Jが負ける
+    }
+
+    #[test]
+    fn test_payoff_check_bet_fold() {
+        // Check-Bet-Fold: P1が1チップ失う
+        assert_eq!(get_payoff(Card::Queen, Card::King, "Check-Bet-Fold"), -1);
+    }
+
+    #[test]
+    fn test_payoff_check_bet_call() {
+        // Check-Bet-Call: ショーダウン、ポット=4
+        assert_eq!(get_payoff(Card::King, Card::Queen, "Check-Bet-Call"), 2);
+        assert_eq!(get_payoff(Card::Jack, Card::Queen, "Check-Bet-Call"), -2);
+    }
+
+    #[test]
+    fn test_info_set_key_generation() {
+        assert_eq!(make_info_set_key('J', ""), "J");
+        assert_eq!(make_info_set_key('Q', "Check"), "Q-Check");
+        assert_eq!(make_info_set_key('K', "Bet-Call"), "K-Bet-Call");
+    }
+
+    #[test]
+    fn test_kuhn_game_info_set_key() {
+        let deal = (Card::Jack, Card::Queen);
+        let state = KuhnGame::initial_state();
+        assert_eq!(KuhnGame::info_set_key(&deal, &state), "J");
+
+        let state = KuhnGame::next_state(&state, Action::Check);
+        assert_eq!(KuhnGame::info_set_key(&deal, &state), "Q-Check");
+    }
 }
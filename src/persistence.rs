@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fs::File;
+use std::hash::Hash;
+use std::io;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::cfr::{CFRNode, InfoSetMap};
+use crate::game::Game;
+
+/// JSONに書き出すための`CFRNode`の表現
+///
+/// `regret_sum`/`strategy_sum`/`actions`は学習を再開するために、
+/// `average_strategy`はファイルを見ただけで（再学習せずに）戦略を確認できるように書き出す。
+/// 読み込み時は`average_strategy`を使わず、生データから`CFRNode`を復元する。
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedNode<A: Copy + Eq + Hash + Debug> {
+    regret_sum: HashMap<A, f64>,
+    strategy_sum: HashMap<A, f64>,
+    actions: Vec<A>,
+    average_strategy: HashMap<A, f64>,
+}
+
+impl<A: Copy + Eq + Hash + Debug> From<&CFRNode<A>> for PersistedNode<A> {
+    fn from(node: &CFRNode<A>) -> Self {
+        Self {
+            regret_sum: node.regret_sum.clone(),
+            strategy_sum: node.strategy_sum.clone(),
+            actions: node.actions.clone(),
+            average_strategy: node.get_average_strategy(),
+        }
+    }
+}
+
+impl<A: Copy + Eq + Hash + Debug> From<PersistedNode<A>> for CFRNode<A> {
+    fn from(persisted: PersistedNode<A>) -> Self {
+        CFRNode {
+            regret_sum: persisted.regret_sum,
+            strategy_sum: persisted.strategy_sum,
+            actions: persisted.actions,
+        }
+    }
+}
+
+/// 学習済みの`InfoSetMap`をJSONファイルに保存する
+///
+/// 各情報集合のキーと、`regret_sum`/`strategy_sum`（学習再開用）に加えて
+/// `get_average_strategy()`の結果（閲覧用）をそのまま書き出すため、
+/// 保存したファイルを`load_strategy`で読み込めば学習を再開できるし、
+/// ファイルをそのまま見れば再学習せずに戦略を確認できる。
+pub fn save_strategy<G>(info_sets: &InfoSetMap<G>, path: impl AsRef<Path>) -> io::Result<()>
+where
+    G: Game,
+    G::InfoSetKey: Serialize,
+    G::Action: Serialize,
+{
+    let persisted: HashMap<&G::InfoSetKey, PersistedNode<G::Action>> = info_sets
+        .iter()
+        .map(|(key, node)| (key, PersistedNode::from(node)))
+        .collect();
+
+    let file = File::create(path)?;
+    serde_json::to_writer_pretty(file, &persisted)?;
+    Ok(())
+}
+
+/// JSONファイルから`InfoSetMap`を読み込む
+pub fn load_strategy<G>(path: impl AsRef<Path>) -> io::Result<InfoSetMap<G>>
+where
+    G: Game,
+    G::InfoSetKey: for<'de> Deserialize<'de>,
+    G::Action: for<'de> Deserialize<'de>,
+{
+    let file = File::open(path)?;
+    let persisted: HashMap<G::InfoSetKey, PersistedNode<G::Action>> = serde_json::from_reader(file)?;
+    Ok(persisted.into_iter().map(|(key, node)| (key, node.into())).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfr::train;
+    use crate::kuhn::KuhnGame;
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let info_sets = train::<KuhnGame>(1_000);
+
+        let path = std::env::temp_dir().join("tiny_gto_test_strategy.json");
+        save_strategy::<KuhnGame>(&info_sets, &path).unwrap();
+        let loaded = load_strategy::<KuhnGame>(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(info_sets.len(), loaded.len());
+
+        for (key, node) in &info_sets {
+            let loaded_node = loaded.get(key).expect("key missing after round trip");
+            assert_eq!(node.regret_sum, loaded_node.regret_sum);
+            assert_eq!(node.strategy_sum, loaded_node.strategy_sum);
+            assert_eq!(node.get_average_strategy(), loaded_node.get_average_strategy());
+        }
+    }
+
+    #[test]
+    fn test_saved_file_contains_average_strategy() {
+        let info_sets = train::<KuhnGame>(1_000);
+
+        let path = std::env::temp_dir().join("tiny_gto_test_strategy_avg.json");
+        save_strategy::<KuhnGame>(&info_sets, &path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(contents.contains("average_strategy"));
+    }
+
+    #[test]
+    fn test_load_missing_file_errors() {
+        let result = load_strategy::<KuhnGame>("/nonexistent/tiny-gto-strategy.json");
+        assert!(result.is_err());
+    }
+}
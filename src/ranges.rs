@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use crate::cfr::InfoSetMap;
+use crate::game::Player;
+use crate::kuhn::{make_info_set_key, Action, Card, KuhnGame};
+
+/// 情報集合キー（例: "Q-Check-Bet"）を、自分のカードと履歴のアクション列に分解する
+fn parse_info_set_key(key: &str) -> (Card, Vec<Action>) {
+    let mut parts = key.split('-');
+    let my_card = Card::from_char(parts.next().unwrap().chars().next().unwrap());
+    let actions = parts.map(parse_action).collect();
+    (my_card, actions)
+}
+
+fn parse_action(s: &str) -> Action {
+    match s {
+        "Check" => Action::Check,
+        "Bet" => Action::Bet,
+        "Call" => Action::Call,
+        "Fold" => Action::Fold,
+        _ => panic!("invalid action in info set key: {s}"),
+    }
+}
+
+/// 情報集合キー`key`における、相手が持つ手札の事後分布(ベイズ推定)を計算する
+///
+/// `key`で示される手番に至るまでの履歴の各アクションのうち、相手が選んだものについて
+/// `info_sets`の平均戦略から選択確率を求め、チャンスの事前確率（残りカードに対する一様分布）
+/// との積をとる。これを相手の手札候補ごとに正規化したものがレンジ（手札分布）になる。
+pub fn opponent_range(info_sets: &InfoSetMap<KuhnGame>, key: &str) -> HashMap<Card, f64> {
+    let (my_card, history) = parse_info_set_key(key);
+
+    // 自分の手番は、履歴の長さから偶奇で決まる（P1から開始して交互に手番が回る）
+    let my_turn = if history.len() % 2 == 0 { Player::Player1 } else { Player::Player2 };
+
+    let opponent_candidates: Vec<Card> = Card::all().into_iter().filter(|&c| c != my_card).collect();
+    let chance_prior = 1.0 / opponent_candidates.len() as f64;
+
+    let mut weights: HashMap<Card, f64> = HashMap::new();
+    for opponent_card in opponent_candidates {
+        let mut reach = 1.0;
+        let mut history_so_far = String::new();
+
+        for (i, &action) in history.iter().enumerate() {
+            let acting_player = if i % 2 == 0 { Player::Player1 } else { Player::Player2 };
+
+            if acting_player != my_turn {
+                // 相手のアクション: 平均戦略から選択確率を求めてリーチ確率に掛ける
+                let opponent_key = make_info_set_key(opponent_card.to_char(), &history_so_far);
+                let prob = match info_sets.get(&opponent_key) {
+                    Some(node) => *node.get_average_strategy().get(&action).unwrap_or(&0.0),
+                    // 未学習の情報集合（学習中に到達しなかった手順）は均等戦略とみなす
+                    None => 0.5,
+                };
+                reach *= prob;
+            }
+
+            history_so_far = if history_so_far.is_empty() {
+                action.to_string()
+            } else {
+                format!("{history_so_far}-{action}")
+            };
+        }
+
+        weights.insert(opponent_card, chance_prior * reach);
+    }
+
+    let total: f64 = weights.values().sum();
+    if total > 0.0 {
+        for weight in weights.values_mut() {
+            *weight /= total;
+        }
+    } else {
+        let n = weights.len() as f64;
+        for weight in weights.values_mut() {
+            *weight = 1.0 / n;
+        }
+    }
+
+    weights
+}
+
+/// GTO戦略を、各情報集合における相手のレンジと合わせて表示する
+pub fn print_strategy_with_ranges(info_sets: &InfoSetMap<KuhnGame>) {
+    println!("\n=== GTO Strategy (with opponent ranges) ===\n");
+
+    let mut keys: Vec<&String> = info_sets.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        let node = info_sets.get(key).unwrap();
+        let avg_strategy = node.get_average_strategy();
+
+        println!("Information Set: {key}");
+
+        let mut actions: Vec<&Action> = avg_strategy.keys().collect();
+        actions.sort_by_key(|a| format!("{:?}", a));
+        for action in actions {
+            let prob = avg_strategy.get(action).unwrap();
+            println!("  {:?}: {:.2}%", action, prob * 100.0);
+        }
+
+        let range = opponent_range(info_sets, key);
+        let mut cards: Vec<&Card> = range.keys().collect();
+        cards.sort_by_key(|c| c.rank());
+        print!("  Opponent range:");
+        for card in cards {
+            print!("  {}: {:.1}%", card.to_char(), range.get(card).unwrap() * 100.0);
+        }
+        println!("\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfr::train;
+
+    #[test]
+    fn test_opponent_range_normalizes_to_one() {
+        let info_sets = train::<KuhnGame>(1_000);
+        let range = opponent_range(&info_sets, "J");
+        let total: f64 = range.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_opponent_range_excludes_my_own_card() {
+        let info_sets = train::<KuhnGame>(1_000);
+        let range = opponent_range(&info_sets, "Q");
+        assert_eq!(range.len(), 2);
+        assert!(!range.contains_key(&Card::Queen));
+    }
+
+    #[test]
+    fn test_opponent_range_updates_after_a_bet() {
+        // 学習が十分進めば、相手がBetした後のレンジはBetする前よりキング寄りになるはず
+        let info_sets = train::<KuhnGame>(50_000);
+
+        let prior = opponent_range(&info_sets, "J");
+        let after_bet = opponent_range(&info_sets, "J-Bet");
+
+        assert!(after_bet[&Card::King] >= prior[&Card::King]);
+    }
+}
@@ -0,0 +1,173 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+
+use crate::cfr::InfoSetMap;
+use crate::game::{Game, Player};
+use crate::kuhn::{Action, Card, KuhnGame};
+
+/// 学習済み/固定の戦略でアクションを選択するプレイヤー
+///
+/// `play_hand`/`simulate` はこのトレイトを通してエージェントとやり取りするため、
+/// ランダムに打つエージェントと学習済みGTO戦略からサンプリングするエージェントを
+/// 同じ土俵で対戦させられる。
+pub trait Agent {
+    /// 情報集合キーと合法手を受け取り、選択するアクションを返す
+    fn act(&mut self, info_set_key: &str, legal: &[Action]) -> Action;
+}
+
+/// 合法手から一様ランダムにアクションを選ぶエージェント
+pub struct RandomAgent {
+    rng: ChaChaRng,
+}
+
+impl RandomAgent {
+    /// シードを指定してランダムエージェントを作成
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: ChaChaRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Agent for RandomAgent {
+    fn act(&mut self, _info_set_key: &str, legal: &[Action]) -> Action {
+        *legal.choose(&mut self.rng).unwrap()
+    }
+}
+
+/// 学習済みの`InfoSetMap`から平均戦略をサンプリングして打つエージェント
+pub struct GtoAgent<'a> {
+    info_sets: &'a InfoSetMap<KuhnGame>,
+    rng: ChaChaRng,
+}
+
+impl<'a> GtoAgent<'a> {
+    /// 学習済み情報集合マップとシードを指定してGTOエージェントを作成
+    pub fn new(info_sets: &'a InfoSetMap<KuhnGame>, seed: u64) -> Self {
+        Self {
+            info_sets,
+            rng: ChaChaRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl<'a> Agent for GtoAgent<'a> {
+    fn act(&mut self, info_set_key: &str, legal: &[Action]) -> Action {
+        // 未学習の情報集合（未到達の手順）にはランダムに打つ
+        let node = match self.info_sets.get(info_set_key) {
+            Some(node) => node,
+            None => return *legal.choose(&mut self.rng).unwrap(),
+        };
+
+        let strategy = node.get_average_strategy();
+        let r: f64 = self.rng.gen();
+        let mut cumulative = 0.0;
+        for action in legal {
+            cumulative += strategy.get(action).copied().unwrap_or(0.0);
+            if r < cumulative {
+                return *action;
+            }
+        }
+
+        // 浮動小数誤差で累積が1.0未満に留まった場合は最後の合法手を返す
+        *legal.last().unwrap()
+    }
+}
+
+/// 指定したシードでカードを配り、2人のエージェントで1ハンドをプレイする
+/// deck_seed: カードを配るためのシード
+/// agent_p1, agent_p2: 各プレイヤーを操作するエージェント
+/// 返り値: プレイヤー1から見た報酬
+pub fn play_hand(deck_seed: u64, agent_p1: &mut dyn Agent, agent_p2: &mut dyn Agent) -> i32 {
+    let mut rng = ChaChaRng::seed_from_u64(deck_seed);
+    let mut deck = [Card::Jack, Card::Queen, Card::King];
+    deck.shuffle(&mut rng);
+    let deal = (deck[0], deck[1]);
+
+    let mut state = KuhnGame::initial_state();
+    while !KuhnGame::is_terminal(&state) {
+        let legal = KuhnGame::legal_actions(&state);
+        let info_set_key = KuhnGame::info_set_key(&deal, &state);
+
+        let action = match KuhnGame::current_player(&state) {
+            Player::Player1 => agent_p1.act(&info_set_key, &legal),
+            Player::Player2 => agent_p2.act(&info_set_key, &legal),
+        };
+
+        state = KuhnGame::next_state(&state, action);
+    }
+
+    KuhnGame::payoff(&deal, &state)
+}
+
+/// シード`seed`から`n_deals`ハンドを対戦させ、プレイヤー1から見た平均報酬を返す
+/// 各ハンドは`seed..seed + n_deals`のシードでカードを配るため、同じ引数なら再現できる
+pub fn simulate(n_deals: u64, seed: u64, agent_p1: &mut dyn Agent, agent_p2: &mut dyn Agent) -> f64 {
+    let total: i64 = (0..n_deals)
+        .map(|i| play_hand(seed + i, agent_p1, agent_p2) as i64)
+        .sum();
+
+    total as f64 / n_deals as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfr::train;
+
+    #[test]
+    fn test_random_agent_picks_legal_action() {
+        let mut agent = RandomAgent::new(42);
+        let legal = vec![Action::Check, Action::Bet];
+        let action = agent.act("J", &legal);
+        assert!(legal.contains(&action));
+    }
+
+    #[test]
+    fn test_random_agent_is_deterministic_for_seed() {
+        let mut agent_a = RandomAgent::new(7);
+        let mut agent_b = RandomAgent::new(7);
+        let legal = vec![Action::Check, Action::Bet];
+
+        let a = agent_a.act("J", &legal);
+        let b = agent_b.act("J", &legal);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_play_hand_is_deterministic_for_seed() {
+        let mut p1_a = RandomAgent::new(1);
+        let mut p2_a = RandomAgent::new(2);
+        let result_a = play_hand(99, &mut p1_a, &mut p2_a);
+
+        let mut p1_b = RandomAgent::new(1);
+        let mut p2_b = RandomAgent::new(2);
+        let result_b = play_hand(99, &mut p1_b, &mut p2_b);
+
+        assert_eq!(result_a, result_b);
+    }
+
+    #[test]
+    fn test_simulate_averages_over_n_deals() {
+        let mut p1 = RandomAgent::new(1);
+        let mut p2 = RandomAgent::new(2);
+        let mean = simulate(200, 0, &mut p1, &mut p2);
+
+        // ランダムAI同士の場合、ショーダウンの損益は配りについて打ち消し合い、
+        // Bet-Fold(+1, 確率1/4)とCheck-Bet-Fold(-1, 確率1/8)の差で期待値は+0.125になる
+        // 200ハンドの平均はこの期待値の近くに収まるので、絶対値は1.0未満になる
+        assert!(mean.abs() < 1.0);
+    }
+
+    #[test]
+    fn test_gto_agent_vs_random_agent() {
+        let info_sets = train::<KuhnGame>(1_000);
+        let mut gto = GtoAgent::new(&info_sets, 1);
+        let mut random = RandomAgent::new(2);
+
+        // 対戦が最後まで完走し、有限の平均報酬が返ることを確認
+        let mean = simulate(50, 0, &mut gto, &mut random);
+        assert!(mean.is_finite());
+    }
+}